@@ -0,0 +1,68 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::http::fetch;
+
+/// Query the servers configured via `DEBUGINFOD_URLS` for the separate debug file matching
+/// build-id `id`, caching any successful download to disk.
+///
+/// Servers are read from the `DEBUGINFOD_URLS` environment variable, semicolon- or
+/// space-separated as in elfutils' debuginfod client. Downloaded files are cached under
+/// `DEBUGINFOD_CACHE_PATH`, falling back to `$XDG_CACHE_HOME/debuginfod_client`, so repeated
+/// lookups for the same build-id don't hit the network again.
+pub(crate) fn locate_debuginfod_build_id(id: &[u8]) -> Option<PathBuf> {
+    let urls = std::env::var("DEBUGINFOD_URLS").ok()?;
+
+    let hex_id = hex_encode(id);
+
+    let cache_file = cache_path(&hex_id)?;
+    if cache_file.exists() {
+        return Some(cache_file);
+    }
+
+    for server in urls.split([';', ' ']).filter(|s| !s.is_empty()) {
+        let url = format!("{}/buildid/{}/debuginfo", server.trim_end_matches('/'), hex_id);
+        if let Some(body) = fetch(&url) {
+            if let Some(parent) = cache_file.parent() {
+                if fs::create_dir_all(parent).is_ok() && fs::write(&cache_file, body).is_ok() {
+                    return Some(cache_file);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Format `id` as lowercase hex, as used in debuginfod build-id URLs and cache paths.
+fn hex_encode(id: &[u8]) -> String {
+    let mut hex_id = String::with_capacity(id.len() * 2);
+    for byte in id {
+        let _ = write!(&mut hex_id, "{:02x}", byte);
+    }
+    hex_id
+}
+
+fn cache_path(hex_id: &str) -> Option<PathBuf> {
+    let base = if let Ok(path) = std::env::var("DEBUGINFOD_CACHE_PATH") {
+        PathBuf::from(path)
+    } else if let Ok(xdg_cache) = std::env::var("XDG_CACHE_HOME") {
+        PathBuf::from(xdg_cache).join("debuginfod_client")
+    } else {
+        PathBuf::from(std::env::var("HOME").ok()?)
+            .join(".cache")
+            .join("debuginfod_client")
+    };
+    Some(base.join(hex_id).join("debuginfo"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_encode_is_lowercase_and_zero_padded() {
+        assert_eq!(hex_encode(&[0x00, 0x0f, 0xab, 0xcd]), "000fabcd");
+    }
+}
@@ -0,0 +1,13 @@
+use std::io::Read;
+
+/// GET `url`, returning the response body on a 200 and `None` for a 404 or any transport error
+/// so the caller can move on to the next candidate.
+pub(crate) fn fetch(url: &str) -> Option<Vec<u8>> {
+    let response = ureq::get(url).call().ok()?;
+    if response.status() != 200 {
+        return None;
+    }
+    let mut body = Vec::new();
+    response.into_reader().read_to_end(&mut body).ok()?;
+    Some(body)
+}
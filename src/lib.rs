@@ -2,11 +2,19 @@
 
 use anyhow::{anyhow, Error};
 use core::convert::TryInto;
-use object::Object;
+use object::{Object, ObjectSection};
+use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fmt::Write;
 use std::fs;
+use std::io::BufRead;
 use std::path::{Path, PathBuf};
 
+// Shared by the `debuginfod` and `symsrv` strategies; neither is compiled in by default, so
+// this stays out of the dependency graph unless one of them is enabled.
+#[cfg(any(feature = "debuginfod", feature = "symsrv"))]
+mod http;
+
 pub type Uuid = [u8; 16];
 
 cfg_if::cfg_if! {
@@ -39,6 +47,18 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "debuginfod")] {
+        mod debuginfod;
+        use crate::debuginfod::locate_debuginfod_build_id;
+    } else {
+        #[allow(clippy::unnecessary_wraps)]
+        fn locate_debuginfod_build_id(_id: &[u8]) -> Option<PathBuf> {
+            None
+        }
+    }
+}
+
 /// On macOS it can take some time for spotlight to index the dSYM file and on other OSes it is
 /// impossible to use spotlight. When built by cargo, we can likely find the dSYM file in
 /// target/<profile>/deps or target/<profile>/examples. Otherwise it can likely be found at
@@ -116,83 +136,626 @@ fn try_match_dsym(dsym_dir: &Path, uuid: Uuid) -> Option<PathBuf> {
     }
 }
 
-/// Attempt to locate the path to separate debug symbols for `object` at `path`.
-///
-/// If `object` does not contain information that can be used to locate debug symbols for it,
-/// or if the debug symbol file is not present on disk, return None.
+/// Key under which a lookup result is cached by [`SymbolLocator`], one variant per strategy.
+#[derive(PartialEq, Eq, Hash)]
+enum CacheKey {
+    Dsym(Uuid, PathBuf),
+    Pdb([u8; 16], u32),
+    BuildId(Vec<u8>),
+    GnuDebugLink(PathBuf, PathBuf, u32),
+    Breakpad(OsString, String),
+    GnuDebugAltLink(PathBuf, Vec<u8>),
+}
+
+/// A configurable, cached entry point for locating separate debug symbol files.
 ///
-/// Currently only locating Mach-O dSYM bundles is supported.
-pub fn locate_debug_symbols<'a, O, T>(object: &'a O, path: T) -> Result<Option<PathBuf>, Error>
+/// A locator holds an ordered list of extra search roots, an optional override for the
+/// `/usr/lib/debug`-style debug root, toggles for which strategies to attempt, and a cache of
+/// past lookups so that repeatedly resolving the same module doesn't re-stat the filesystem.
+/// Build one with [`SymbolLocator::new`] and the builder methods, then call its `locate_*`
+/// methods in place of the free functions of the same name.
+pub struct SymbolLocator {
+    search_paths: Vec<PathBuf>,
+    debug_root: Option<PathBuf>,
+    try_dsym: bool,
+    try_pdb: bool,
+    try_build_id: bool,
+    try_gnu_debuglink: bool,
+    try_breakpad: bool,
+    cache: HashMap<CacheKey, Option<PathBuf>>,
+}
+
+impl Default for SymbolLocator {
+    fn default() -> Self {
+        SymbolLocator {
+            search_paths: Vec::new(),
+            debug_root: None,
+            try_dsym: true,
+            try_pdb: true,
+            try_build_id: true,
+            try_gnu_debuglink: true,
+            try_breakpad: true,
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl SymbolLocator {
+    /// Create a locator with every strategy enabled, no extra search paths and an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a directory to search for debug files, in addition to each strategy's default
+    /// locations. Searched in the order added, after the default directories but before the
+    /// Spotlight (dSYM) and debuginfod (build-id) fallbacks.
+    pub fn search_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.search_paths.push(path.into());
+        self
+    }
+
+    /// Override the `/usr/lib/debug`-style root used to resolve build-id and debuglink files,
+    /// in place of `/usr/lib/debug`.
+    pub fn debug_root<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.debug_root = Some(path.into());
+        self
+    }
+
+    /// Enable or disable the Mach-O dSYM strategy. Enabled by default.
+    pub fn try_dsym(mut self, enabled: bool) -> Self {
+        self.try_dsym = enabled;
+        self
+    }
+
+    /// Enable or disable the PE/PDB strategy. Enabled by default.
+    pub fn try_pdb(mut self, enabled: bool) -> Self {
+        self.try_pdb = enabled;
+        self
+    }
+
+    /// Enable or disable the ELF build-id strategy. Enabled by default.
+    pub fn try_build_id(mut self, enabled: bool) -> Self {
+        self.try_build_id = enabled;
+        self
+    }
+
+    /// Enable or disable the ELF `.gnu_debuglink` strategy. Enabled by default.
+    pub fn try_gnu_debuglink(mut self, enabled: bool) -> Self {
+        self.try_gnu_debuglink = enabled;
+        self
+    }
+
+    /// Enable or disable the Google Breakpad `.sym` strategy. Enabled by default.
+    pub fn try_breakpad(mut self, enabled: bool) -> Self {
+        self.try_breakpad = enabled;
+        self
+    }
+
+    /// Attempt to locate the path to separate debug symbols for `object` at `path`, trying
+    /// each enabled strategy in turn.
+    ///
+    /// If `object` does not contain information that can be used to locate debug symbols for
+    /// it, or if the debug symbol file is not present on disk, return None.
+    pub fn locate_debug_symbols<'a, O, T>(
+        &mut self,
+        object: &'a O,
+        path: T,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        O: Object<'a, 'a>,
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        if self.try_dsym {
+            if let Some(uuid) = object.mach_uuid()? {
+                if let Some(found) = self.locate_dsym(path, uuid)? {
+                    return Ok(Some(found));
+                }
+            }
+        }
+        if self.try_pdb {
+            if let Some(pdbinfo) = object.pdb_info()? {
+                if let Some(found) = self.locate_pdb(path, &pdbinfo)? {
+                    return Ok(Some(found));
+                }
+            }
+        }
+        if self.try_build_id {
+            if let Some(found) = object
+                .build_id()?
+                .and_then(|build_id| self.locate_debug_build_id(build_id))
+            {
+                return Ok(Some(found));
+            }
+        }
+        if self.try_gnu_debuglink {
+            if let Some((filename, crc)) = object.gnu_debuglink()? {
+                let filename = path_from_bytes(filename)?;
+                if let Some(found) = self.locate_gnu_debuglink(path, filename, crc)? {
+                    return Ok(Some(found));
+                }
+            }
+        }
+        if self.try_breakpad {
+            if let Some(found) = self.locate_breakpad_sym(object, path)? {
+                return Ok(Some(found));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Attempt to locate the Mach-O file contained within a dSYM bundle containing the debug
+    /// symbols for the Mach-O file at `path` with UUID `uuid`.
+    pub fn locate_dsym<T>(&mut self, path: T, uuid: Uuid) -> Result<Option<PathBuf>, Error>
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let key = CacheKey::Dsym(uuid, path.to_path_buf());
+        if let Some(cached) = self.cache.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let result = self.locate_dsym_uncached(path, uuid)?;
+        self.cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    fn locate_dsym_uncached(&self, path: &Path, uuid: Uuid) -> Result<Option<PathBuf>, Error> {
+        if let Some(dsym_path) = locate_dsym_fastpath(path, uuid) {
+            return Ok(Some(dsym_path));
+        }
+
+        if let Some(file_name) = path.file_name() {
+            for root in &self.search_paths {
+                let mut dsym_name = file_name.to_owned();
+                dsym_name.push(".dSYM");
+                if let Some(dsym_path) = try_match_dsym(&root.join(dsym_name), uuid) {
+                    return Ok(Some(dsym_path));
+                }
+            }
+        }
+
+        locate_dsym_using_spotlight(uuid)
+    }
+
+    /// Attempt to locate the PDB file for an executable that is at `path` with the
+    /// pdb infomation stored in `pdbinfo`.
+    pub fn locate_pdb<T>(
+        &mut self,
+        path: T,
+        pdbinfo: &object::CodeView,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        T: AsRef<Path>,
+    {
+        let key = CacheKey::Pdb(pdbinfo.guid(), pdbinfo.age());
+        if let Some(cached) = self.cache.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let result = self.locate_pdb_uncached(path, pdbinfo)?;
+        self.cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    fn locate_pdb_uncached<T>(
+        &self,
+        path: T,
+        pdbinfo: &object::CodeView,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        T: AsRef<Path>,
+    {
+        // Search order taken from here:
+        // https://docs.microsoft.com/en-us/windows/win32/debug/symbol-paths
+
+        // First check path in PE file
+        let codeview_path = PathBuf::from(path_from_bytes(pdbinfo.path())?);
+        if try_match_pdb(pdbinfo.guid(), pdbinfo.age(), &codeview_path)? {
+            return Ok(Some(codeview_path));
+        }
+
+        // Next check _NT_SYMBOL_PATH env var
+        if let Some(path) = locate_pdb_from_env_var(&path, "_NT_SYMBOL_PATH", pdbinfo)? {
+            return Ok(Some(path));
+        }
+
+        // Next check _NT_ALT_SYMBOL_PATH env var
+        if let Some(path) = locate_pdb_from_env_var(&path, "_NT_ALT_SYMBOL_PATH", pdbinfo)? {
+            return Ok(Some(path));
+        }
+
+        // Next check module directory
+        let canonical_path = fs::canonicalize(&path)?;
+        if let Some(search_path) = canonical_path.parent() {
+            if let Some(path) = locate_pdb_in_search_path(&path, search_path.as_os_str(), pdbinfo)?
+            {
+                return Ok(Some(path));
+            }
+        }
+
+        // Finally check the locator's extra search paths
+        for root in &self.search_paths {
+            if let Some(path) = locate_pdb_in_search_path(&path, root.as_os_str(), pdbinfo)? {
+                return Ok(Some(path));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Attempt to locate the separate debug symbol file for the object file at `path` with
+    /// build ID `id`.
+    ///
+    /// Checks the debug root (`/usr/lib/debug` unless overridden) and the locator's extra
+    /// search paths first, then falls back to querying `DEBUGINFOD_URLS` servers if the
+    /// `debuginfod` feature is enabled.
+    pub fn locate_debug_build_id(&mut self, id: &[u8]) -> Option<PathBuf> {
+        if id.len() < 2 {
+            return None;
+        }
+
+        let key = CacheKey::BuildId(id.to_vec());
+        if let Some(cached) = self.cache.get(&key) {
+            return cached.clone();
+        }
+
+        let result = self.locate_debug_build_id_uncached(id);
+        self.cache.insert(key, result.clone());
+        result
+    }
+
+    fn locate_debug_build_id_uncached(&self, id: &[u8]) -> Option<PathBuf> {
+        let debug_root = self.debug_root_or_default();
+        let f = build_id_debug_path(&debug_root, id);
+        if f.exists() {
+            return Some(f);
+        }
+
+        for root in &self.search_paths {
+            let f = build_id_debug_path(root, id);
+            if f.exists() {
+                return Some(f);
+            }
+        }
+
+        // Fall back to querying debuginfod servers (opt-in via the `debuginfod` feature).
+        locate_debuginfod_build_id(id)
+    }
+
+    /// Attempt to locate the separate debug symbol file for the object file at `path` with
+    /// GNU "debug link" information consisting of `filename` and `crc`.
+    pub fn locate_gnu_debuglink<T, U>(
+        &mut self,
+        path: T,
+        filename: U,
+        crc: u32,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        T: AsRef<Path>,
+        U: AsRef<Path>,
+    {
+        let path = fs::canonicalize(path)?;
+        let filename = filename.as_ref().to_path_buf();
+        let key = CacheKey::GnuDebugLink(path.clone(), filename.clone(), crc);
+        if let Some(cached) = self.cache.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let result = self.locate_gnu_debuglink_uncached(&path, &filename, crc)?;
+        self.cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    fn locate_gnu_debuglink_uncached(
+        &self,
+        path: &Path,
+        filename: &Path,
+        crc: u32,
+    ) -> Result<Option<PathBuf>, Error> {
+        let parent = path.parent().ok_or_else(|| anyhow!("Bad path"))?;
+
+        // Try "/parent/filename" if it differs from "path"
+        let f = parent.join(filename);
+        if f != path && debuglink_crc_matches(&f, crc) {
+            return Ok(Some(f));
+        }
+
+        // Try "/parent/.debug/filename"
+        let f = parent.join(".debug").join(filename);
+        if debuglink_crc_matches(&f, crc) {
+            return Ok(Some(f));
+        }
+
+        // Try "<debug_root>/parent/filename"
+        let stripped_parent = parent.strip_prefix("/").unwrap_or(parent);
+        let f = self.debug_root_or_default().join(stripped_parent).join(filename);
+        if debuglink_crc_matches(&f, crc) {
+            return Ok(Some(f));
+        }
+
+        // Finally check the locator's extra search paths
+        for root in &self.search_paths {
+            let f = root.join(filename);
+            if debuglink_crc_matches(&f, crc) {
+                return Ok(Some(f));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn debug_root_or_default(&self) -> PathBuf {
+        self.debug_root
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("/usr/lib/debug"))
+    }
+
+    /// Attempt to locate a Google Breakpad `.sym` text symbol file for `object` at `path`,
+    /// searching the locator's search paths for
+    /// `{search_path}/{debug_file}/{debug_id}/{debug_file}.sym`.
+    ///
+    /// The debug identifier is derived from whichever of a PDB GUID+age, a Mach-O UUID or an
+    /// ELF build-id is present on `object`; if none is present, this returns `Ok(None)`. When
+    /// the id came from a PDB, `debug_file` is the PDB's own basename (e.g. `app.pdb`), matching
+    /// the Breakpad/Socorro convention, rather than the basename of `path` (e.g. `app.exe`).
+    pub fn locate_breakpad_sym<'a, O, T>(
+        &mut self,
+        object: &'a O,
+        path: T,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        O: Object<'a, 'a>,
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let debug_id = match breakpad_debug_id(object)? {
+            Some(id) => id,
+            None => return Ok(None),
+        };
+        let debug_file = match object.pdb_info()? {
+            Some(pdbinfo) => {
+                let pdb_path = path_from_bytes(pdbinfo.path())?;
+                Path::new(pdb_path)
+                    .file_name()
+                    .ok_or_else(|| anyhow!("Bad PDB path"))?
+                    .to_owned()
+            }
+            None => match path.file_name() {
+                Some(name) => name.to_owned(),
+                None => return Ok(None),
+            },
+        };
+
+        let key = CacheKey::Breakpad(debug_file.clone(), debug_id.clone());
+        if let Some(cached) = self.cache.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let mut sym_name = debug_file.clone();
+        sym_name.push(".sym");
+
+        let mut result = None;
+        for root in &self.search_paths {
+            let candidate = root.join(&debug_file).join(&debug_id).join(&sym_name);
+            if breakpad_sym_matches(&candidate, &debug_id) {
+                result = Some(candidate);
+                break;
+            }
+        }
+
+        self.cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    /// Attempt to locate the DWZ supplementary debug file referenced by `object`'s
+    /// `.gnu_debugaltlink` section: a NUL-terminated filename followed by a 20-byte build-id.
+    ///
+    /// The filename (which may be relative) is resolved against the primary debug file's own
+    /// directory first; if that doesn't match, the build-id is resolved the same way as for
+    /// primary debug files (the `.build-id/XX/YYYY.debug` layout, and debuginfod if enabled).
+    /// Either candidate is only accepted once its own build-id matches the one from the
+    /// section.
+    pub fn locate_gnu_debugaltlink<'a, O, T>(
+        &mut self,
+        object: &'a O,
+        path: T,
+    ) -> Result<Option<PathBuf>, Error>
+    where
+        O: Object<'a, 'a>,
+        T: AsRef<Path>,
+    {
+        let path = fs::canonicalize(path)?;
+        let section = match object.section_by_name(".gnu_debugaltlink") {
+            Some(section) => section,
+            None => return Ok(None),
+        };
+        let data = section.data()?;
+        let (filename, build_id) = match parse_gnu_debugaltlink(data) {
+            Some(parsed) => parsed,
+            None => return Ok(None),
+        };
+
+        let key = CacheKey::GnuDebugAltLink(path.clone(), build_id.to_vec());
+        if let Some(cached) = self.cache.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let result = self.locate_gnu_debugaltlink_uncached(&path, filename, build_id)?;
+        self.cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    fn locate_gnu_debugaltlink_uncached(
+        &mut self,
+        path: &Path,
+        filename: &[u8],
+        build_id: &[u8],
+    ) -> Result<Option<PathBuf>, Error> {
+        let filename = path_from_bytes(filename)?;
+
+        if let Some(parent) = path.parent() {
+            let candidate = parent.join(filename);
+            if object_build_id_matches(&candidate, build_id) {
+                return Ok(Some(candidate));
+            }
+        }
+
+        if let Some(candidate) = self.locate_debug_build_id(build_id) {
+            if object_build_id_matches(&candidate, build_id) {
+                return Ok(Some(candidate));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Split a `.gnu_debugaltlink` section into its NUL-terminated filename and 20-byte build-id.
+fn parse_gnu_debugaltlink(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let nul = data.iter().position(|&b| b == 0)?;
+    let (filename, rest) = data.split_at(nul);
+    let build_id = &rest[1..];
+    if build_id.len() == 20 {
+        Some((filename, build_id))
+    } else {
+        None
+    }
+}
+
+/// Returns true if `path` can be parsed as an object file whose build-id equals `expected`.
+fn object_build_id_matches(path: &Path, expected: &[u8]) -> bool {
+    let data = match fs::read(path) {
+        Ok(data) => data,
+        Err(_) => return false,
+    };
+    let file = match object::File::parse(&data[..]) {
+        Ok(file) => file,
+        Err(_) => return false,
+    };
+    matches!(file.build_id(), Ok(Some(id)) if id == expected)
+}
+
+/// Derive the Breakpad debug identifier for `object` from whichever of a PDB GUID+age, a
+/// Mach-O UUID or an ELF build-id is present, in that order.
+fn breakpad_debug_id<'a, O>(object: &'a O) -> Result<Option<String>, Error>
 where
     O: Object<'a, 'a>,
-    T: AsRef<Path>,
 {
+    if let Some(pdbinfo) = object.pdb_info()? {
+        let guid = pdbinfo.guid();
+        let uuid = uuid::Uuid::from_fields(
+            u32::from_le_bytes(guid[0..4].try_into().unwrap()),
+            u16::from_le_bytes(guid[4..6].try_into().unwrap()),
+            u16::from_le_bytes(guid[6..8].try_into().unwrap()),
+            &guid[8..16],
+        )?;
+        return Ok(Some(breakpad_id_from_bytes(
+            uuid.as_bytes(),
+            pdbinfo.age(),
+        )));
+    }
     if let Some(uuid) = object.mach_uuid()? {
-        return locate_dsym(path.as_ref(), uuid);
+        return Ok(Some(breakpad_id_from_bytes(&uuid, 0)));
     }
-    if let Some(pdbinfo) = object.pdb_info()? {
-        return locate_pdb(path.as_ref(), &pdbinfo);
+    if let Some(build_id) = object.build_id()? {
+        return Ok(Some(breakpad_id_from_bytes(build_id, 0)));
+    }
+    Ok(None)
+}
+
+/// Format `bytes` (truncated or zero-padded to 16 bytes) and `age` as the 32-hex-char-plus-age
+/// Breakpad debug identifier, e.g. `"492E3EF6D2EC4F638F4CC9284B3D11E31"`.
+fn breakpad_id_from_bytes(bytes: &[u8], age: u32) -> String {
+    let mut id = String::with_capacity(33);
+    let mut bytes = bytes.iter().copied().chain(std::iter::repeat(0));
+    for _ in 0..16 {
+        let _ = write!(&mut id, "{:02X}", bytes.next().unwrap());
     }
-    if let Some(path) = object
-        .build_id()?
-        .and_then(|build_id| locate_debug_build_id(build_id))
+    let _ = write!(&mut id, "{:X}", age);
+    id
+}
+
+/// Returns true if `path` is a Breakpad `.sym` file whose first `MODULE` record's debug id
+/// matches `debug_id`.
+fn breakpad_sym_matches(path: &Path, debug_id: &str) -> bool {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(_) => return false,
+    };
+
+    let mut first_line = String::new();
+    if std::io::BufReader::new(file)
+        .read_line(&mut first_line)
+        .is_err()
     {
-        return Ok(Some(path));
-        // If not found, try gnu_debuglink.
+        return false;
     }
-    if let Some((filename, crc)) = object.gnu_debuglink()? {
-        let filename = path_from_bytes(filename)?;
-        return locate_gnu_debuglink(path.as_ref(), filename, crc);
+
+    let mut fields = first_line.split_whitespace();
+    if fields.next() != Some("MODULE") {
+        return false;
     }
-    Ok(None)
+    fields.next(); // operating system
+    fields.next(); // architecture
+    matches!(fields.next(), Some(id) if id.eq_ignore_ascii_case(debug_id))
+}
+
+/// Build the `<root>/.build-id/xx/yyyyyyyy.debug` path for build-id `id` under `root`.
+fn build_id_debug_path(root: &Path, id: &[u8]) -> PathBuf {
+    let mut rest = String::with_capacity((id.len() - 1) * 2 + ".debug".len());
+    for x in &id[1..] {
+        let _ = write!(&mut rest, "{:02x}", x);
+    }
+    rest.push_str(".debug");
+    root.join(".build-id")
+        .join(format!("{:02x}", id[0]))
+        .join(rest)
+}
+
+/// Attempt to locate the path to separate debug symbols for `object` at `path` using a
+/// default-configured [`SymbolLocator`] (every strategy enabled, no extra search paths).
+///
+/// If `object` does not contain information that can be used to locate debug symbols for it,
+/// or if the debug symbol file is not present on disk, return None.
+pub fn locate_debug_symbols<'a, O, T>(object: &'a O, path: T) -> Result<Option<PathBuf>, Error>
+where
+    O: Object<'a, 'a>,
+    T: AsRef<Path>,
+{
+    SymbolLocator::new().locate_debug_symbols(object, path)
 }
 
 /// Attempt to locate the Mach-O file contained within a dSYM bundle containing the debug
-/// symbols for the Mach-O file at `path` with UUID `uuid`.
+/// symbols for the Mach-O file at `path` with UUID `uuid`, using a default-configured
+/// [`SymbolLocator`].
 pub fn locate_dsym<T>(path: T, uuid: Uuid) -> Result<Option<PathBuf>, Error>
 where
     T: AsRef<Path>,
 {
-    if let Some(dsym_path) = locate_dsym_fastpath(path.as_ref(), uuid) {
-        return Ok(Some(dsym_path));
-    }
-    locate_dsym_using_spotlight(uuid)
+    SymbolLocator::new().locate_dsym(path, uuid)
 }
 
 /// Attempt to locate the PDB file for an executable that is at `path` with the
-/// pdb infomation stored in `pdbinfo`.
+/// pdb infomation stored in `pdbinfo`, using a default-configured [`SymbolLocator`].
 pub fn locate_pdb<T>(path: T, pdbinfo: &object::CodeView) -> Result<Option<PathBuf>, Error>
 where
     T: AsRef<Path>,
 {
-    // Search order taken from here:
-    // https://docs.microsoft.com/en-us/windows/win32/debug/symbol-paths
-
-    // First check path in PE file
-    let codeview_path = PathBuf::from(path_from_bytes(pdbinfo.path())?);
-    if try_match_pdb(pdbinfo.guid(), pdbinfo.age(), &codeview_path)? {
-        return Ok(Some(codeview_path));
-    }
-
-    // Next check _NT_SYMBOL_PATH env var
-    if let Some(path) = locate_pdb_from_env_var(&path, "_NT_SYMBOL_PATH", pdbinfo)? {
-        return Ok(Some(path));
-    }
-
-    // Next check _NT_ALT_SYMBOL_PATH env var
-    if let Some(path) = locate_pdb_from_env_var(&path, "_NT_ALT_SYMBOL_PATH", pdbinfo)? {
-        return Ok(Some(path));
-    }
-
-    // Next check module directory
-    let path = fs::canonicalize(path)?;
-    if let Some(search_path) = path.parent() {
-        if let Some(path) = locate_pdb_in_search_path(&path, search_path.as_os_str(), pdbinfo)? {
-            return Ok(Some(path));
-        }
-    }
+    SymbolLocator::new().locate_pdb(path, pdbinfo)
+}
 
-    Ok(None)
+/// Attempt to locate the DWZ supplementary debug file referenced by `object`'s
+/// `.gnu_debugaltlink` section, using a default-configured [`SymbolLocator`].
+pub fn locate_gnu_debugaltlink<'a, O, T>(object: &'a O, path: T) -> Result<Option<PathBuf>, Error>
+where
+    O: Object<'a, 'a>,
+    T: AsRef<Path>,
+{
+    SymbolLocator::new().locate_gnu_debugaltlink(object, path)
 }
 
 fn locate_pdb_from_env_var<T>(
@@ -227,8 +790,7 @@ where
 {
     if let Some(search_path) = search_path.to_str() {
         if search_path.starts_with("srv*") || search_path.starts_with("cache*") {
-            // Currently srv* and cache* are unsupported
-            return Ok(None);
+            return locate_pdb_from_symbol_server(search_path, pdbinfo);
         }
     }
 
@@ -278,6 +840,117 @@ where
     Ok(None)
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "symsrv")] {
+        /// Resolve a `srv*`/`cache*` symbol-server chain as used in `_NT_SYMBOL_PATH`, e.g.
+        /// `srv*C:\sym*https://msdl.microsoft.com/download/symbols`.
+        ///
+        /// The chain is a `*`-separated list of local cache directories followed by one or more
+        /// HTTP(S) server roots. Each cache directory is checked first; on a miss, the PDB is
+        /// downloaded from each server in turn and written into the first writable cache
+        /// directory, falling back to the system temp directory if none was configured.
+        fn locate_pdb_from_symbol_server(
+            chain: &str,
+            pdbinfo: &object::CodeView,
+        ) -> Result<Option<PathBuf>, Error> {
+            let mut elements = chain.split('*');
+            elements.next(); // "srv" or "cache"
+
+            let mut cache_dirs = Vec::new();
+            let mut servers = Vec::new();
+            for element in elements {
+                if element.is_empty() {
+                    continue;
+                }
+                if element.starts_with("http://") || element.starts_with("https://") {
+                    servers.push(element.trim_end_matches('/'));
+                } else {
+                    cache_dirs.push(PathBuf::from(element));
+                }
+            }
+
+            let filename = path_from_bytes(pdbinfo.path())?;
+            let filename = Path::new(filename)
+                .file_name()
+                .ok_or_else(|| anyhow!("Bad PDB path"))?;
+            let key = Path::new(filename)
+                .join(pdb_symbol_server_id(pdbinfo.guid(), pdbinfo.age())?)
+                .join(filename);
+
+            for cache_dir in &cache_dirs {
+                let candidate = cache_dir.join(&key);
+                if try_match_pdb(pdbinfo.guid(), pdbinfo.age(), &candidate)? {
+                    return Ok(Some(candidate));
+                }
+            }
+
+            for server in &servers {
+                let url = format!("{}/{}", server, key.to_string_lossy().replace('\\', "/"));
+                if let Some(body) = http::fetch(&url) {
+                    // Fall back to the system temp directory when the chain has no local
+                    // cache element (e.g. `srv*https://msdl.microsoft.com/download/symbols`),
+                    // so a successful download is still returned rather than discarded.
+                    let targets = if cache_dirs.is_empty() {
+                        vec![std::env::temp_dir()]
+                    } else {
+                        cache_dirs.clone()
+                    };
+                    for cache_dir in &targets {
+                        let candidate = cache_dir.join(&key);
+                        let written = candidate.parent().map_or(false, |parent| {
+                            fs::create_dir_all(parent).is_ok() && fs::write(&candidate, &body).is_ok()
+                        });
+                        if !written {
+                            continue;
+                        }
+                        // The server may have returned an error page, a redirect stub, or
+                        // a PDB for the wrong GUID/age (e.g. a captive portal or
+                        // misconfigured proxy); reuse the same check as the cache-dir
+                        // lookup above rather than trusting a 200 status on its own.
+                        if try_match_pdb(pdbinfo.guid(), pdbinfo.age(), &candidate)? {
+                            return Ok(Some(candidate));
+                        }
+                        let _ = fs::remove_file(&candidate);
+                        break;
+                    }
+                }
+            }
+
+            Ok(None)
+        }
+
+        /// Format the GUID/age pair as the `{GUID}{AGE}` path component used by Microsoft
+        /// symbol servers: the GUID in canonical display order (the same byte-swap
+        /// `try_match_pdb` and `breakpad_debug_id` apply) as 32 uppercase hex digits, followed
+        /// by the age as uppercase hex with no leading zeros.
+        fn pdb_symbol_server_id(guid: [u8; 16], age: u32) -> Result<String, Error> {
+            let canonical = uuid::Uuid::from_fields(
+                u32::from_le_bytes(guid[0..4].try_into().unwrap()),
+                u16::from_le_bytes(guid[4..6].try_into().unwrap()),
+                u16::from_le_bytes(guid[6..8].try_into().unwrap()),
+                &guid[8..16],
+            )?;
+
+            let mut id = String::with_capacity(40);
+            for byte in canonical.as_bytes() {
+                let _ = write!(&mut id, "{:02X}", byte);
+            }
+            let _ = write!(&mut id, "{:X}", age);
+            Ok(id)
+        }
+    } else {
+        /// Symbol-server lookups are opt-in via the `symsrv` feature; without it, `srv*`/
+        /// `cache*` entries in a symbol search path are simply skipped.
+        #[allow(clippy::unnecessary_wraps)]
+        fn locate_pdb_from_symbol_server(
+            _chain: &str,
+            _pdbinfo: &object::CodeView,
+        ) -> Result<Option<PathBuf>, Error> {
+            Ok(None)
+        }
+    }
+}
+
 fn try_match_pdb(guid: [u8; 16], age: u32, path: &Path) -> Result<bool, Error> {
     let file = match std::fs::File::open(path) {
         Ok(file) => file,
@@ -299,57 +972,115 @@ fn try_match_pdb(guid: [u8; 16], age: u32, path: &Path) -> Result<bool, Error> {
 }
 
 /// Attempt to locate the separate debug symbol file for the object file at `path` with
-/// build ID `id`.
+/// build ID `id`, using a default-configured [`SymbolLocator`].
+///
+/// Checks the local `/usr/lib/debug/.build-id/` layout first, then falls back to querying
+/// `DEBUGINFOD_URLS` servers if the `debuginfod` feature is enabled.
 pub fn locate_debug_build_id(id: &[u8]) -> Option<PathBuf> {
-    if id.len() < 2 {
-        return None;
-    }
-
-    // Try "/usr/lib/debug/.build-id/12/345678etc.debug"
-    let mut f = format!("/usr/lib/debug/.build-id/{:02x}/", id[0]);
-    for x in &id[1..] {
-        let _ = write!(&mut f, "{:02x}", x);
-    }
-    let _ = write!(&mut f, ".debug");
-    let f = PathBuf::from(f);
-    if f.exists() {
-        return Some(f);
-    }
-
-    None
+    SymbolLocator::new().locate_debug_build_id(id)
 }
 
 /// Attempt to locate the separate debug symbol file for the object file at `path` with
-/// GNU "debug link" information consisting of `filename` and `crc`.
-pub fn locate_gnu_debuglink<T, U>(path: T, filename: U, _crc: u32) -> Result<Option<PathBuf>, Error>
+/// GNU "debug link" information consisting of `filename` and `crc`, using a
+/// default-configured [`SymbolLocator`].
+pub fn locate_gnu_debuglink<T, U>(path: T, filename: U, crc: u32) -> Result<Option<PathBuf>, Error>
 where
     T: AsRef<Path>,
     U: AsRef<Path>,
 {
-    let path = fs::canonicalize(path)?;
-    let parent = path.parent().ok_or_else(|| anyhow!("Bad path"))?;
-    let filename = filename.as_ref();
+    SymbolLocator::new().locate_gnu_debuglink(path, filename, crc)
+}
 
-    // TODO: check CRC
+/// Returns true if `path` can be read and its contents have the GNU debuglink CRC-32 `crc`.
+/// Any error opening or reading the file is treated as a non-match rather than propagated, so
+/// callers can keep searching the remaining candidates.
+fn debuglink_crc_matches(path: &Path, crc: u32) -> bool {
+    match fs::read(path) {
+        Ok(contents) => debuglink_crc32(&contents) == crc,
+        Err(_) => false,
+    }
+}
 
-    // Try "/parent/filename" if it differs from "path"
-    let f = parent.join(filename);
-    if f != path && f.exists() {
-        return Ok(Some(f));
+/// Compute the CRC-32 (IEEE 802.3 / zlib polynomial 0xEDB88320) of `data`, as used by the
+/// `.gnu_debuglink` section to checksum the whole contents of the linked debug file.
+fn debuglink_crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
     }
+    !crc
+}
 
-    // Try "/parent/.debug/filename"
-    let f = parent.join(".debug").join(filename);
-    if f.exists() {
-        return Ok(Some(f));
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn debuglink_crc32_matches_known_vector() {
+        // Standard CRC-32/ISO-HDLC check value for the ASCII string "123456789".
+        assert_eq!(debuglink_crc32(b"123456789"), 0xCBF4_3926);
     }
 
-    // Try "/usr/lib/debug/parent/filename"
-    let parent = parent.strip_prefix("/").unwrap();
-    let f = Path::new("/usr/lib/debug").join(parent).join(filename);
-    if f.exists() {
-        return Ok(Some(f));
+    #[test]
+    fn build_id_debug_path_splits_off_the_first_byte() {
+        let id = [0xab, 0xcd, 0xef, 0x01, 0x23];
+        assert_eq!(
+            build_id_debug_path(Path::new("/usr/lib/debug"), &id),
+            Path::new("/usr/lib/debug/.build-id/ab/cdef0123.debug")
+        );
     }
 
-    Ok(None)
+    #[test]
+    fn breakpad_id_from_bytes_zero_pads_short_ids_and_appends_age_as_hex() {
+        assert_eq!(
+            breakpad_id_from_bytes(&[0xde, 0xad, 0xbe, 0xef], 0x2a),
+            "DEADBEEF0000000000000000000000002A"
+        );
+    }
+
+    #[test]
+    fn parse_gnu_debugaltlink_splits_filename_and_build_id() {
+        let build_id: [u8; 20] = [
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+            0x0f, 0x10, 0x11, 0x12, 0x13, 0x14,
+        ];
+        let mut data = b"libfoo.debug".to_vec();
+        data.push(0);
+        data.extend_from_slice(&build_id);
+        assert_eq!(
+            parse_gnu_debugaltlink(&data),
+            Some((&b"libfoo.debug"[..], &build_id[..]))
+        );
+    }
+
+    #[test]
+    fn parse_gnu_debugaltlink_rejects_missing_nul_or_wrong_length_build_id() {
+        assert_eq!(parse_gnu_debugaltlink(b"no-nul-terminator"), None);
+        assert_eq!(parse_gnu_debugaltlink(b"libfoo.debug\0"), None);
+        // Build-id present but shorter than the required 20 bytes.
+        assert_eq!(
+            parse_gnu_debugaltlink(b"libfoo.debug\0\x01\x02\x03\x04"),
+            None
+        );
+    }
+
+    #[cfg(feature = "symsrv")]
+    #[test]
+    fn pdb_symbol_server_id_uses_canonical_guid_order() {
+        // `guid` as `object::CodeView::guid()` would return it: Data1/Data2/Data3 in the
+        // little-endian order the PE RSDS record stores them in, not canonical display order.
+        let guid: [u8; 16] = [
+            0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab,
+            0xcd, 0xef,
+        ];
+        assert_eq!(
+            pdb_symbol_server_id(guid, 3).unwrap(),
+            "78563412BC9AF0DE0123456789ABCDEF3"
+        );
+    }
 }